@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use strum::{EnumIter, IntoEnumIterator};
 use thiserror::Error;
 
@@ -27,6 +29,16 @@ pub enum UnoWildCard {
     Unplayed { draw_4: bool },
 }
 
+impl UnoWildCard {
+    /// Whether this is a `Draw4` wild, regardless of whether a color has been chosen yet.
+    #[must_use]
+    pub fn is_draw_4(&self) -> bool {
+        match self {
+            UnoWildCard::Played { draw_4, .. } | UnoWildCard::Unplayed { draw_4 } => *draw_4,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, EnumIter, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum UnoColor {
@@ -43,6 +55,115 @@ pub enum UnoCard {
     Wild(UnoWildCard),
 }
 
+impl UnoValue {
+    /// Point value of this card's face, used for end-of-round scoring.
+    /// Number cards score their face value, action cards (`Skip`, `Reverse`, `Draw2`)
+    /// score 20.
+    #[must_use]
+    pub fn score(&self) -> u32 {
+        match self {
+            UnoValue::Zero => 0,
+            UnoValue::One => 1,
+            UnoValue::Two => 2,
+            UnoValue::Three => 3,
+            UnoValue::Four => 4,
+            UnoValue::Five => 5,
+            UnoValue::Six => 6,
+            UnoValue::Seven => 7,
+            UnoValue::Eight => 8,
+            UnoValue::Nine => 9,
+            UnoValue::Skip | UnoValue::Reverse | UnoValue::Draw2 => 20,
+        }
+    }
+}
+
+/// Errors describing why a short text card notation (e.g. `"r5"`, `"wd4:b"`) could not
+/// be parsed.
+#[derive(Debug, Error)]
+pub enum UnoCardParseError {
+    /// Input string was empty
+    #[error("Card notation cannot be empty")]
+    Empty,
+    /// Unrecognized color code; expected one of `r`, `g`, `b`, `y`
+    #[error("Unrecognized color code: {0}")]
+    InvalidColor(String),
+    /// Unrecognized value code
+    #[error("Unrecognized value code: {0}")]
+    InvalidValue(String),
+    /// Malformed wild card notation; expected `w`, `wd4`, `w:<color>`, or `wd4:<color>`
+    #[error("Malformed wild card notation: {0}")]
+    InvalidWild(String),
+}
+
+impl fmt::Display for UnoValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnoValue::Zero => write!(f, "0"),
+            UnoValue::One => write!(f, "1"),
+            UnoValue::Two => write!(f, "2"),
+            UnoValue::Three => write!(f, "3"),
+            UnoValue::Four => write!(f, "4"),
+            UnoValue::Five => write!(f, "5"),
+            UnoValue::Six => write!(f, "6"),
+            UnoValue::Seven => write!(f, "7"),
+            UnoValue::Eight => write!(f, "8"),
+            UnoValue::Nine => write!(f, "9"),
+            UnoValue::Skip => write!(f, "s"),
+            UnoValue::Reverse => write!(f, "r"),
+            UnoValue::Draw2 => write!(f, "d2"),
+        }
+    }
+}
+
+impl FromStr for UnoValue {
+    type Err = UnoCardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(UnoValue::Zero),
+            "1" => Ok(UnoValue::One),
+            "2" => Ok(UnoValue::Two),
+            "3" => Ok(UnoValue::Three),
+            "4" => Ok(UnoValue::Four),
+            "5" => Ok(UnoValue::Five),
+            "6" => Ok(UnoValue::Six),
+            "7" => Ok(UnoValue::Seven),
+            "8" => Ok(UnoValue::Eight),
+            "9" => Ok(UnoValue::Nine),
+            "s" => Ok(UnoValue::Skip),
+            "r" => Ok(UnoValue::Reverse),
+            "d2" => Ok(UnoValue::Draw2),
+            _ => Err(UnoCardParseError::InvalidValue(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for UnoColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            UnoColor::Red => 'r',
+            UnoColor::Green => 'g',
+            UnoColor::Blue => 'b',
+            UnoColor::Yellow => 'y',
+        };
+        write!(f, "{code}")
+    }
+}
+
+impl FromStr for UnoColor {
+    type Err = UnoCardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "r" => Ok(UnoColor::Red),
+            "g" => Ok(UnoColor::Green),
+            "b" => Ok(UnoColor::Blue),
+            "y" => Ok(UnoColor::Yellow),
+            _ => Err(UnoCardParseError::InvalidColor(s.to_string())),
+        }
+    }
+}
+
 /// Errors describing why one card cannot be played on another.
 #[derive(Debug, Error)]
 pub enum UnoCardMatchError {
@@ -100,6 +221,31 @@ impl UnoCard {
         }
     }
 
+    /// Point value of this card, used for end-of-round scoring. Number and action cards
+    /// score per [`UnoValue::score`]; both wild variants score 50 regardless of whether
+    /// they've been played yet.
+    #[must_use]
+    pub fn score(&self) -> u32 {
+        match self {
+            UnoCard::Card { value, .. } => value.score(),
+            UnoCard::Wild(_) => 50,
+        }
+    }
+
+    /// Whether `self`, as found in a player's hand, is the card `played` claims to be.
+    /// Wild cards in hand are always [`UnoWildCard::Unplayed`] (dealt straight from the
+    /// deck), so a wild in hand matches a played wild of the same `draw_4`-ness
+    /// regardless of the color chosen for the play; every other card must match exactly.
+    #[must_use]
+    pub(crate) fn matches_played(&self, played: UnoCard) -> bool {
+        match (self, played) {
+            (UnoCard::Wild(hand_wild), UnoCard::Wild(played_wild)) => {
+                hand_wild.is_draw_4() == played_wild.is_draw_4()
+            }
+            _ => *self == played,
+        }
+    }
+
     /// Get all permutations of `UnoCard::Card` by combining [`UnoColor`]s with [`UnoValue`]s.
     /// Returns a list of [`UnoCard`]s representing one each of:
     /// - All 4 colors, 0-9
@@ -114,3 +260,124 @@ impl UnoCard {
             .collect::<Vec<_>>()
     }
 }
+
+/// Renders as a compact, human-typeable notation: color letter + value code for normal
+/// cards (`"r5"`, `"ys"`, `"bd2"`), `"w"`/`"wd4"` for an unplayed wild, and
+/// `"w:<color>"`/`"wd4:<color>"` for a wild that has had a color chosen.
+impl fmt::Display for UnoCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnoCard::Card { color, value } => write!(f, "{color}{value}"),
+            UnoCard::Wild(UnoWildCard::Unplayed { draw_4: false }) => write!(f, "w"),
+            UnoCard::Wild(UnoWildCard::Unplayed { draw_4: true }) => write!(f, "wd4"),
+            UnoCard::Wild(UnoWildCard::Played {
+                draw_4: false,
+                color,
+            }) => write!(f, "w:{color}"),
+            UnoCard::Wild(UnoWildCard::Played {
+                draw_4: true,
+                color,
+            }) => write!(f, "wd4:{color}"),
+        }
+    }
+}
+
+impl FromStr for UnoCard {
+    type Err = UnoCardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(UnoCardParseError::Empty);
+        }
+
+        if let Some(rest) = s.strip_prefix('w') {
+            let (draw_4, rest) = match rest.strip_prefix("d4") {
+                Some(rest) => (true, rest),
+                None => (false, rest),
+            };
+
+            return if rest.is_empty() {
+                Ok(UnoCard::Wild(UnoWildCard::Unplayed { draw_4 }))
+            } else if let Some(color_code) = rest.strip_prefix(':') {
+                Ok(UnoCard::Wild(UnoWildCard::Played {
+                    draw_4,
+                    color: color_code.parse()?,
+                }))
+            } else {
+                Err(UnoCardParseError::InvalidWild(s.to_string()))
+            };
+        }
+
+        let mut chars = s.chars();
+        let color_code = chars.next().ok_or(UnoCardParseError::Empty)?;
+        let color: UnoColor = color_code.to_string().parse()?;
+        let value: UnoValue = chars.as_str().parse()?;
+
+        Ok(UnoCard::Card { color, value })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_parse_roundtrips_every_normal_card() {
+        for color in UnoColor::iter() {
+            for value in UnoValue::iter() {
+                let card = UnoCard::Card { color, value };
+                assert_eq!(card.to_string().parse::<UnoCard>().unwrap(), card);
+            }
+        }
+    }
+
+    #[test]
+    fn display_parse_roundtrips_every_wild_card() {
+        for draw_4 in [false, true] {
+            let unplayed = UnoCard::Wild(UnoWildCard::Unplayed { draw_4 });
+            assert_eq!(unplayed.to_string().parse::<UnoCard>().unwrap(), unplayed);
+
+            for color in UnoColor::iter() {
+                let played = UnoCard::Wild(UnoWildCard::Played { draw_4, color });
+                assert_eq!(played.to_string().parse::<UnoCard>().unwrap(), played);
+            }
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_malformed_notation() {
+        assert!(matches!(
+            "".parse::<UnoCard>(),
+            Err(UnoCardParseError::Empty)
+        ));
+        assert!(matches!(
+            "z5".parse::<UnoCard>(),
+            Err(UnoCardParseError::InvalidColor(_))
+        ));
+        assert!(matches!(
+            "rz".parse::<UnoCard>(),
+            Err(UnoCardParseError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            "w:".parse::<UnoCard>(),
+            Err(UnoCardParseError::InvalidColor(_))
+        ));
+    }
+
+    #[test]
+    fn matches_played_ignores_wild_color_but_not_draw_4() {
+        let hand_wild = UnoCard::Wild(UnoWildCard::Unplayed { draw_4: false });
+        let played = UnoCard::Wild(UnoWildCard::Played {
+            draw_4: false,
+            color: UnoColor::Blue,
+        });
+        assert!(hand_wild.matches_played(played));
+
+        let played_draw_4 = UnoCard::Wild(UnoWildCard::Played {
+            draw_4: true,
+            color: UnoColor::Blue,
+        });
+        assert!(!hand_wild.matches_played(played_draw_4));
+    }
+}
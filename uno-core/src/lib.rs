@@ -2,19 +2,27 @@
 
 pub mod cards;
 
-use cards::{UnoCard, UnoCardMatchError, UnoValue, UnoWildCard};
+use cards::{UnoCard, UnoCardMatchError, UnoColor, UnoValue, UnoWildCard};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::{borrow::BorrowMut, iter, usize};
 use thiserror::Error;
 
 pub const FULL_DECK_SIZE: usize = 108;
-pub const PLAYER_STARTING_HAND_SIZE: usize = 108;
+/// Default number of cards dealt to each player at the start of a round.
+pub const PLAYER_STARTING_HAND_SIZE: usize = 7;
+/// Maximum number of players a single game supports.
+pub const MAX_PLAYERS: usize = 10;
+/// Default total score a player must reach to win an overall match played across
+/// multiple rounds.
+pub const DEFAULT_TARGET_SCORE: u32 = 500;
 
 #[derive(Debug, Error)]
 pub enum UnoError {
     /// Not enough cards to deal to this many players
-    #[error("Too many players: max 4, attempted {0}")]
+    #[error("Invalid number of players: must be 1 to 10, attempted {0}")]
     TooManyPlayers(usize),
     /// Not enough cards left to deal
     #[error("No cards left")]
@@ -28,15 +36,102 @@ pub enum UnoError {
     /// Chosen card doesn't match discard pile top card
     #[error("Chosen card doesn't match the top card of the discard pile")]
     CardNotPlayable(#[from] UnoCardMatchError),
+    /// The match has already finished, no further plays are accepted
+    #[error("The match has already finished")]
+    GameOver,
+    /// A Draw2/Draw4 stack is pending and the played card doesn't match its type
+    #[error("Only a matching Draw2/Draw4 can be played while a draw stack is pending")]
+    InvalidStackedDraw,
+    /// The current round has ended; call [`UnoGameState::start_next_round`] before
+    /// playing again
+    #[error("The current round has ended; start the next round before playing again")]
+    RoundOver,
+    /// [`UnoGameState::start_next_round`] was called, but the current round hasn't
+    /// ended yet
+    #[error("The current round is still in progress")]
+    RoundInProgress,
+    /// A player tried to play out of turn without a valid `jump_in`
+    #[error("It is not this player's turn, and the played card is not a valid jump-in")]
+    NotYourTurn,
+    /// Played a `Seven` under the `seven_zero` rule without specifying which player to
+    /// swap hands with
+    #[error("Playing a Seven requires choosing another player to swap hands with")]
+    MissingSwapTarget,
+}
+
+/// Configurable house rules, since official Uno rules vary widely between groups.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameRules {
+    /// Allow stacking `Draw2` on `Draw2` and wild `Draw4` on `Draw4`, passing the
+    /// accumulated draw penalty down the line instead of resolving it immediately.
+    pub stacking: bool,
+    /// Allow a player to jump in out of turn by playing an exact duplicate (same
+    /// color and value) of the current discard pile's top card. See
+    /// [`UnoGameState::try_next`].
+    pub jump_in: bool,
+    /// Playing a `Seven` lets the player swap hands with another player; playing a
+    /// `Zero` passes every player's hand to the next player in the turn direction. See
+    /// [`UnoGameState::try_next`].
+    pub seven_zero: bool,
+}
+
+/// Lifecycle state of a [`UnoGameState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameStatus {
+    NotStarted,
+    InProgress,
+    /// `winner` emptied their hand, ending the round, but nobody has reached
+    /// `target_score` yet. Call [`UnoGameState::start_next_round`] to deal a new round
+    /// and continue the match with `scores` carried over.
+    RoundOver { winner: usize },
+    /// `winner` reached `target_score`, ending the match.
+    Finished { winner: usize },
+}
+
+/// A single recorded event in a game's history. Replaying a [`UnoGameState`]'s
+/// `move_log` against the same seed deterministically reconstructs the game, which is
+/// useful for bots and test harnesses that need to debug or verify play.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MoveLog {
+    /// A player played a card, choosing a color if it was a wild and another player
+    /// to swap hands with if it was a `Seven` under the `seven_zero` rule.
+    CardPlayed {
+        player: usize,
+        card: UnoCard,
+        chosen_color: Option<UnoColor>,
+        swap_with: Option<usize>,
+    },
+    /// A player drew `count` cards as a direct effect of a card played against them.
+    CardsDrawn { player: usize, count: usize },
+    /// A player called [`UnoGameState::pass_and_draw`]: they resolved a pending
+    /// `Draw2`/`Draw4` stack by drawing instead of stacking another matching draw card,
+    /// or simply passed their turn if no stack was pending.
+    PassedStack { player: usize },
+    /// `player` was caught failing to call "UNO" while holding exactly one card.
+    UnoCaught { player: usize },
+    /// The current player and/or turn direction changed.
+    TurnChanged {
+        current_turn: usize,
+        direction: TurnDirection,
+    },
+    /// A player was forced to draw `count` cards as a missed-UNO penalty.
+    Penalty { player: usize, count: usize },
+    /// A new round was dealt via [`UnoGameState::start_next_round`] after the previous
+    /// one ended, continuing the match with `scores` carried over.
+    RoundStarted,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct UnoDeck(Vec<UnoCard>);
 
 impl UnoDeck {
-    /// Get a brand new, shuffled full Uno deck.
+    /// Get a brand new, shuffled full Uno deck, shuffled using `rng` so that a game's
+    /// initial deal can be reproduced deterministically from a seed.
     #[must_use]
-    pub fn new() -> Self {
+    pub fn new(rng: &mut StdRng) -> Self {
         let mut cards: Vec<UnoCard> = Vec::with_capacity(FULL_DECK_SIZE);
 
         // 2 sets of 0-9s and special cards for each color, but only 1 zero card per color
@@ -63,7 +158,7 @@ impl UnoDeck {
             cards.push(UnoCard::Wild(UnoWildCard::Unplayed { draw_4: i < 4 }));
         });
 
-        cards.shuffle(&mut rand::thread_rng());
+        cards.shuffle(rng);
 
         UnoDeck(cards)
     }
@@ -74,17 +169,17 @@ impl UnoDeck {
         self.0.pop()
     }
 
-    /// Deal out cards to specified number of players
+    /// Deal `hand_size` cards to each of `players` players, round-robin.
     ///
     /// # Errors
     ///
     /// Returns an error if there are no cards left in the deck.
-    pub fn deal(&mut self, players: usize) -> Result<Vec<UnoDeck>, UnoError> {
-        let mut player_hands = iter::repeat(Vec::<UnoCard>::with_capacity(7))
+    pub fn deal(&mut self, players: usize, hand_size: usize) -> Result<Vec<UnoDeck>, UnoError> {
+        let mut player_hands = iter::repeat(Vec::<UnoCard>::with_capacity(hand_size))
             .take(players)
             .collect::<Vec<_>>();
 
-        for i in 0..(PLAYER_STARTING_HAND_SIZE * players) {
+        for i in 0..(hand_size * players) {
             // deal cards round-robin style to each player, one at a time
             player_hands[i % players].push(self.draw_card().ok_or(UnoError::NoCardsLeft)?);
         }
@@ -94,20 +189,20 @@ impl UnoDeck {
 
     /// Put the discard deck back into the deck and reshuffle.
     #[must_use]
-    pub fn from_discard(discard_deck: &UnoDeck) -> UnoDeck {
+    pub fn from_discard(discard_deck: &UnoDeck, rng: &mut StdRng) -> UnoDeck {
         let mut cards = discard_deck.0.clone();
-        cards.shuffle(&mut rand::thread_rng());
+        cards.shuffle(rng);
         UnoDeck(cards)
     }
 }
 
 impl Default for UnoDeck {
     fn default() -> Self {
-        Self::new()
+        Self::new(&mut StdRng::from_entropy())
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TurnDirection {
     Clockwise,
@@ -122,102 +217,506 @@ pub struct UnoGameState {
     pub player_hands: Vec<UnoDeck>,
     pub turn_direction: TurnDirection,
     pub current_turn: usize,
+    /// Accumulated score for each player across rounds, indexed by player number.
+    pub scores: Vec<u32>,
+    /// Total score a player must reach to win the overall match.
+    pub target_score: u32,
+    /// Number of cards dealt to each player at the start of every round, including
+    /// rounds dealt by [`UnoGameState::start_next_round`].
+    pub hand_size: usize,
+    /// Current lifecycle state of the game.
+    pub status: GameStatus,
+    /// Whether each player has called "UNO" since their hand last became exactly one
+    /// card, indexed by player number.
+    pub uno_called: Vec<bool>,
+    /// Configurable house rules in effect for this game.
+    pub rules: GameRules,
+    /// Number of cards the current player must draw to resolve a pending stacked
+    /// `Draw2`/`Draw4`, or 0 if no stack is pending.
+    pub pending_draw: usize,
+    /// Whether the pending stack (if any) is a `Draw4` stack (`true`) or a `Draw2`
+    /// stack (`false`). Only meaningful while `pending_draw > 0`.
+    pub pending_draw_is_draw4: bool,
+    /// Log of every event that has occurred this game, in order. Replaying this log
+    /// against `seed` via [`UnoGameState::replay`] deterministically reconstructs the
+    /// game, except that [`UnoGameState::call_uno`] calls are not logged, so a replayed
+    /// game's `uno_called` can differ.
+    pub move_log: Vec<MoveLog>,
+    /// Seed used to initialize this game's shuffling RNG.
+    pub seed: u64,
+    /// Seeded RNG used for all shuffling, so that games are reproducible from `seed`.
+    /// Not serialized; a deserialized game gets a fresh entropy-seeded RNG, since only
+    /// `seed` plus `move_log` are needed to replay play that has already happened.
+    #[serde(skip, default = "UnoGameState::default_rng")]
+    pub rng: StdRng,
 }
 
 impl UnoGameState {
-    /// Initialize a new game with the specified number of players.
+    fn default_rng() -> StdRng {
+        StdRng::from_entropy()
+    }
+
+    /// Initialize a new game with the specified number of players and house rules,
+    /// dealing [`PLAYER_STARTING_HAND_SIZE`] cards to each player and using a randomly
+    /// generated seed.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `players` is 0 or greater than [`MAX_PLAYERS`], or if there are not
+    /// enough cards. The latter should never happen since decks are combined as needed.
+    pub fn new(players: usize, rules: GameRules) -> Result<Self, UnoError> {
+        Self::new_with_hand_size(players, rules, PLAYER_STARTING_HAND_SIZE)
+    }
+
+    /// Initialize a new game like [`UnoGameState::new`], but dealing `hand_size` cards
+    /// to each player instead of the default [`PLAYER_STARTING_HAND_SIZE`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`UnoGameState::new`].
+    pub fn new_with_hand_size(
+        players: usize,
+        rules: GameRules,
+        hand_size: usize,
+    ) -> Result<Self, UnoError> {
+        let seed = rand::thread_rng().gen();
+        Self::new_with_seed(players, rules, seed, hand_size)
+    }
+
+    /// Initialize a new game with the specified number of players, house rules, hand
+    /// size, and an explicit RNG seed. The same seed and an identical sequence of moves
+    /// always produce the same game, which [`UnoGameState::replay`] relies on.
+    ///
+    /// If `players * hand_size` exceeds a single deck, multiple [`UnoDeck::new`] decks
+    /// are combined and reshuffled together, as official rules allow for large groups.
     ///
     /// # Errors
     ///
-    /// Errors if there are not enough cards. Should never happen
-    /// since we're making a brand new deck.
-    pub fn new(players: usize) -> Result<Self, UnoError> {
-        let mut main_deck = UnoDeck::new();
+    /// Errors if `players` is 0 or greater than [`MAX_PLAYERS`], or if there are not
+    /// enough cards. The latter should never happen since decks are combined as needed.
+    pub fn new_with_seed(
+        players: usize,
+        rules: GameRules,
+        seed: u64,
+        hand_size: usize,
+    ) -> Result<Self, UnoError> {
+        if players == 0 || players > MAX_PLAYERS {
+            return Err(UnoError::TooManyPlayers(players));
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut main_deck = Self::new_combined_deck(&mut rng, players, hand_size);
         // deal to players first
-        let player_hands = main_deck.deal(players)?;
+        let player_hands = main_deck.deal(players, hand_size)?;
         // draw the first card to start the game
         let discard_deck = UnoDeck(vec![main_deck.draw_card().ok_or(UnoError::NoCardsLeft)?]);
         Ok(Self {
+            scores: vec![0; players],
             main_deck,
             discard_deck,
             player_hands,
             turn_direction: TurnDirection::Clockwise,
             current_turn: 0, // TODO dice roll for who goes first
+            target_score: DEFAULT_TARGET_SCORE,
+            hand_size,
+            status: GameStatus::InProgress,
+            uno_called: vec![true; players],
+            rules,
+            pending_draw: 0,
+            pending_draw_is_draw4: false,
+            move_log: Vec::new(),
+            seed,
+            rng,
         })
     }
 
+    /// Build a shuffled main deck with enough cards for `players` players to each be
+    /// dealt `hand_size` cards (plus a starting discard), combining multiple
+    /// [`UnoDeck::new`] decks together if a single deck isn't enough.
+    fn new_combined_deck(rng: &mut StdRng, players: usize, hand_size: usize) -> UnoDeck {
+        let cards_needed = players * hand_size + 1;
+        let decks_needed = cards_needed.saturating_sub(1) / FULL_DECK_SIZE + 1;
+
+        let mut cards = Vec::with_capacity(FULL_DECK_SIZE * decks_needed);
+        for _ in 0..decks_needed {
+            cards.append(&mut UnoDeck::new(rng).0);
+        }
+        cards.shuffle(rng);
+
+        UnoDeck(cards)
+    }
+
+    /// Re-simulate a game from its seed and a recorded move log, validating every
+    /// logged card play through the normal [`UnoGameState::try_next`] path. Gives bots
+    /// and test harnesses a way to debug and verify a game from its JSON replay format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a logged move is not actually valid to replay, or if there
+    /// are not enough cards in a freshly seeded deck. Should not happen for a log
+    /// produced by this crate from a game using [`DEFAULT_TARGET_SCORE`]: the replayed
+    /// game always uses that target, so a log from a game with another `target_score`
+    /// can end rounds differently and fail to replay.
+    pub fn replay(
+        players: usize,
+        rules: GameRules,
+        seed: u64,
+        hand_size: usize,
+        moves: &[MoveLog],
+    ) -> Result<Self, UnoError> {
+        let mut state = Self::new_with_seed(players, rules, seed, hand_size)?;
+        for entry in moves {
+            match entry {
+                MoveLog::CardPlayed {
+                    player,
+                    card,
+                    chosen_color,
+                    swap_with,
+                } => {
+                    let played_card = match (card, chosen_color) {
+                        (UnoCard::Wild(UnoWildCard::Unplayed { draw_4 }), Some(color)) => {
+                            UnoCard::Wild(UnoWildCard::Played {
+                                draw_4: *draw_4,
+                                color: *color,
+                            })
+                        }
+                        _ => *card,
+                    };
+                    state.try_next(*player, &played_card, *swap_with)?;
+                }
+                MoveLog::PassedStack { .. } => {
+                    state.pass_and_draw()?;
+                }
+                MoveLog::UnoCaught { player } => {
+                    state.catch_missed_uno(*player)?;
+                }
+                MoveLog::RoundStarted => {
+                    state.start_next_round()?;
+                }
+                MoveLog::CardsDrawn { .. }
+                | MoveLog::TurnChanged { .. }
+                | MoveLog::Penalty { .. } => {
+                    // recorded as a side effect of a CardPlayed/PassedStack/UnoCaught/
+                    // RoundStarted entry above, nothing further to replay
+                }
+            }
+        }
+        Ok(state)
+    }
+
+    /// Initialize a new game with the specified number of players and a custom
+    /// target score for overall match victory.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`UnoGameState::new`].
+    pub fn new_with_target_score(
+        players: usize,
+        target_score: u32,
+        rules: GameRules,
+    ) -> Result<Self, UnoError> {
+        let mut state = Self::new(players, rules)?;
+        state.target_score = target_score;
+        Ok(state)
+    }
+
     /// Draw `n` cards, handling recycling the main deck from the discard
     /// deck if it runs out.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if there are not enough cards in the discard and main decks combined. TODO fix
-    /// this.
-    pub fn draw_n_cards(&mut self, n: usize) -> Vec<UnoCard> {
+    /// Returns [`UnoError::NoCardsLeft`] if there are not enough cards left in the
+    /// discard and main decks combined (e.g. very large hands with few players).
+    pub fn draw_n_cards(&mut self, n: usize) -> Result<Vec<UnoCard>, UnoError> {
         let mut cards = vec![];
         for _ in 0..n {
             if let Some(card) = self.main_deck.draw_card() {
                 cards.push(card);
             } else {
                 // move all but top card to main deck and shuffle
-                let top_card = self
-                    .discard_deck
-                    .0
-                    .pop()
-                    .expect("No cards left -- everyone has huge hands?"); // TODO handle
-                                                                          // panic
+                let top_card = self.discard_deck.0.pop().ok_or(UnoError::NoCardsLeft)?;
                 self.main_deck.0.append(&mut self.discard_deck.0);
+                // recycled wilds go back to being unplayed, so hands only hold `Unplayed`
+                for card in &mut self.main_deck.0 {
+                    if let UnoCard::Wild(wild) = card {
+                        *wild = UnoWildCard::Unplayed {
+                            draw_4: wild.is_draw_4(),
+                        };
+                    }
+                }
+                self.main_deck.0.shuffle(&mut self.rng);
                 self.discard_deck = UnoDeck(vec![top_card]);
-                cards.push(
-                    self.main_deck
-                        .draw_card()
-                        .expect("There should be cards now."),
-                );
+                cards.push(self.main_deck.draw_card().ok_or(UnoError::NoCardsLeft)?);
             }
         }
 
-        cards
+        Ok(cards)
+    }
+
+    /// Returns the index of the player who has emptied their hand, ending the current
+    /// round, or [`std::option::Option::None`] if the round is still in progress.
+    #[must_use]
+    pub fn is_round_over(&self) -> Option<usize> {
+        self.player_hands.iter().position(|hand| hand.0.is_empty())
+    }
+
+    /// Score the end of a round: `winner` is awarded the summed score of every card
+    /// remaining in all other players' hands, accumulated into `scores`. Returns the
+    /// score awarded.
+    pub fn score_round(&mut self, winner: usize) -> u32 {
+        let round_score: u32 = self
+            .player_hands
+            .iter()
+            .enumerate()
+            .filter(|(player, _)| *player != winner)
+            .flat_map(|(_, hand)| hand.0.iter().map(UnoCard::score))
+            .sum();
+        self.scores[winner] += round_score;
+        round_score
+    }
+
+    /// Declare "UNO" for `player`, satisfying the call requirement incurred when they
+    /// were left holding exactly one card.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnoError::InvalidPlayerNumber`] if `player` is out of range.
+    pub fn call_uno(&mut self, player: usize) -> Result<(), UnoError> {
+        if player > self.player_hands.len() - 1 {
+            return Err(UnoError::InvalidPlayerNumber);
+        }
+        self.uno_called[player] = true;
+        Ok(())
+    }
+
+    /// Returns an error if the game isn't currently accepting plays: the match has
+    /// already finished, or the current round has ended and is awaiting
+    /// [`UnoGameState::start_next_round`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnoError::GameOver`] or [`UnoError::RoundOver`], per above.
+    fn ensure_in_progress(&self) -> Result<(), UnoError> {
+        match self.status {
+            GameStatus::Finished { .. } => Err(UnoError::GameOver),
+            GameStatus::RoundOver { .. } => Err(UnoError::RoundOver),
+            GameStatus::NotStarted | GameStatus::InProgress => Ok(()),
+        }
+    }
+
+    /// Catch `player` for failing to call "UNO" while holding exactly one card. If
+    /// caught, they must immediately draw a 2-card penalty. Returns whether they were
+    /// caught.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnoError::InvalidPlayerNumber`] if `player` is out of range, or
+    /// any error from [`UnoGameState::ensure_in_progress`].
+    pub fn catch_missed_uno(&mut self, player: usize) -> Result<bool, UnoError> {
+        self.ensure_in_progress()?;
+
+        if player > self.player_hands.len() - 1 {
+            return Err(UnoError::InvalidPlayerNumber);
+        }
+        if self.player_hands[player].0.len() == 1 && !self.uno_called[player] {
+            self.move_log.push(MoveLog::UnoCaught { player });
+            let mut penalty = self.draw_n_cards(2)?;
+            self.player_hands[player].0.append(&mut penalty);
+            self.uno_called[player] = true;
+            self.move_log.push(MoveLog::Penalty { player, count: 2 });
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Resolve a pending stacked `Draw2`/`Draw4` for the current player: they draw the
+    /// accumulated `pending_draw` cards, the stack resets to 0, and their turn is
+    /// skipped. A no-op (other than advancing the turn) if no stack is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`UnoGameState::ensure_in_progress`].
+    pub fn pass_and_draw(&mut self) -> Result<&mut Self, UnoError> {
+        self.ensure_in_progress()?;
+
+        self.move_log.push(MoveLog::PassedStack {
+            player: self.current_turn,
+        });
+        if self.pending_draw > 0 {
+            let mut drawn = self.draw_n_cards(self.pending_draw)?;
+            self.move_log.push(MoveLog::CardsDrawn {
+                player: self.current_turn,
+                count: drawn.len(),
+            });
+            self.player_hands[self.current_turn].0.append(&mut drawn);
+            self.pending_draw = 0;
+        }
+        self.current_turn = (self.current_turn + 1) % self.player_hands.len();
+        self.move_log.push(MoveLog::TurnChanged {
+            current_turn: self.current_turn,
+            direction: self.turn_direction,
+        });
+
+        Ok(self)
+    }
+
+    /// Whether `card` is a legal continuation of a pending stacked `Draw2`/`Draw4`: a
+    /// `Draw2` can only stack on a `Draw2` stack, and a played `Draw4` wild can only
+    /// stack on a `Draw4` stack.
+    fn matches_pending_stack(&self, card: UnoCard) -> bool {
+        match card {
+            UnoCard::Card {
+                value: UnoValue::Draw2,
+                ..
+            } => !self.pending_draw_is_draw4,
+            UnoCard::Wild(UnoWildCard::Played { draw_4: true, .. }) => self.pending_draw_is_draw4,
+            _ => false,
+        }
+    }
+
+    /// Whether `card` is an exact duplicate (same color and value) of the current
+    /// discard pile's top card — the requirement for playing out of turn under the
+    /// `jump_in` rule.
+    fn is_exact_duplicate_of_top(&self, card: UnoCard) -> bool {
+        self.discard_deck.0.last() == Some(&card)
+    }
+
+    /// Under `self.rules.seven_zero`, a played `Seven` requires a valid `swap_with`
+    /// target; every other card (or a `Seven` with the rule off) is always valid.
+    fn validate_seven_swap(&self, card: UnoCard, swap_with: Option<usize>) -> Result<(), UnoError> {
+        if !self.rules.seven_zero
+            || !matches!(
+                card,
+                UnoCard::Card {
+                    value: UnoValue::Seven,
+                    ..
+                }
+            )
+        {
+            return Ok(());
+        }
+        match swap_with {
+            Some(other) if other < self.player_hands.len() => Ok(()),
+            Some(_) => Err(UnoError::InvalidPlayerNumber),
+            None => Err(UnoError::MissingSwapTarget),
+        }
     }
 
     /// Try to set the next game state by playing the specified card. Does not modify
     /// state if turn validation fails.
     ///
+    /// A wild in a player's hand is always dealt as [`UnoWildCard::Unplayed`], so to
+    /// play one, pass a [`UnoWildCard::Played`] `which_card` with the same `draw_4` and
+    /// the chosen color — it's matched against the hand by `draw_4` rather than by
+    /// exact equality, since the hand can't already contain the chosen color.
+    ///
+    /// `whos_turn` must be [`UnoGameState::current_turn`], unless `self.rules.jump_in`
+    /// is set and `which_card` exactly duplicates the discard pile's top card, in
+    /// which case any player may jump in and play passes to them.
+    ///
+    /// `swap_with` is the player to swap hands with under `self.rules.seven_zero`;
+    /// it's required when playing a `Seven` under that rule, and ignored otherwise.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     ///
     /// - `whos_turn` is not a valid player number
+    /// - It isn't `whos_turn`'s turn and they don't have a valid `jump_in`
     /// - `which_card` cannot be played on the top of the current discard deck
     /// - The specified player does not have the specified card in their hand
+    /// - `swap_with` is missing or invalid for a `Seven` played under `seven_zero`
+    /// - Any error from [`UnoGameState::ensure_in_progress`]
     pub fn try_next(
         &mut self,
         whos_turn: usize,
         which_card: &UnoCard,
+        swap_with: Option<usize>,
     ) -> Result<&mut Self, UnoError> {
-        // TODO track and account for turn direction and skip turns
+        // TODO track and account for skip turns interacting with jump-in
+        self.ensure_in_progress()?;
+
         if whos_turn > self.player_hands.len() - 1 {
             return Err(UnoError::InvalidPlayerNumber);
         }
 
+        if whos_turn != self.current_turn
+            && !(self.rules.jump_in && self.is_exact_duplicate_of_top(*which_card))
+        {
+            return Err(UnoError::NotYourTurn);
+        }
+
         let player_hand = &self.player_hands[whos_turn];
-        let Some(card_idx) = player_hand.0.iter().position(|card| card == which_card) else {
+        let Some(card_idx) = player_hand
+            .0
+            .iter()
+            .position(|card| card.matches_played(*which_card))
+        else {
             return Err(UnoError::Cheating);
         };
 
+        if self.rules.stacking
+            && self.pending_draw > 0
+            && !self.matches_pending_stack(*which_card)
+        {
+            return Err(UnoError::InvalidStackedDraw);
+        }
+
         let top_card = &self.discard_deck.0[self.discard_deck.0.len() - 1];
         which_card.playable_on(top_card)?;
 
-        let card = self.player_hands[whos_turn].0.remove(card_idx);
+        self.validate_seven_swap(*which_card, swap_with)?;
+
+        let turn_before = (self.current_turn, self.turn_direction);
+
+        // a jump-in plays as though it were already whos_turn's turn
+        self.current_turn = whos_turn;
+
+        // the hand only ever holds `Unplayed` wilds; `which_card` carries the chosen
+        // color (if any), so that's what actually goes on the discard pile
+        self.player_hands[whos_turn].0.remove(card_idx);
+        let card = *which_card;
         self.discard_deck.0.push(card);
+        self.log_card_played(whos_turn, card, swap_with);
+
+        // track whether whos_turn must now call "UNO"
+        self.uno_called[whos_turn] = self.player_hands[whos_turn].0.len() != 1;
+
+        self.apply_card_effect(card, swap_with)?;
+        self.finish_turn(turn_before);
 
+        Ok(self)
+    }
+
+    /// Record that `player` played `card` in the move log, including the color chosen
+    /// if it was a wild and the player swapped hands with, if any.
+    fn log_card_played(&mut self, player: usize, card: UnoCard, swap_with: Option<usize>) {
+        let chosen_color = match card {
+            UnoCard::Wild(UnoWildCard::Played { color, .. }) => Some(color),
+            _ => None,
+        };
+        self.move_log.push(MoveLog::CardPlayed {
+            player,
+            card,
+            chosen_color,
+            swap_with,
+        });
+    }
+
+    /// Apply the turn-order and draw-pile effects of having just played `card`:
+    /// `Skip`/`Reverse` change whose turn it is, `Draw2`/`Draw4` make the next player
+    /// draw (or stack the penalty, if `self.rules.stacking`), and, under
+    /// `self.rules.seven_zero`, `Seven` swaps hands with `swap_with` and `Zero` passes
+    /// every hand to the next player in the turn direction. Other number cards are a
+    /// no-op.
+    fn apply_card_effect(&mut self, card: UnoCard, swap_with: Option<usize>) -> Result<(), UnoError> {
         match card {
             UnoCard::Card { value, .. } => match value {
                 UnoValue::Skip => {
-                    // skip player
                     self.current_turn = (self.current_turn + 2) % self.player_hands.len();
                 }
                 UnoValue::Reverse => {
-                    // reverse
                     self.turn_direction = match self.turn_direction {
                         TurnDirection::Clockwise => {
                             self.current_turn = if self.current_turn == 0 {
@@ -233,36 +732,251 @@ impl UnoGameState {
                         }
                     }
                 }
-                UnoValue::Draw2 => {
-                    // advance turn
-                    self.current_turn = (self.current_turn + 1) % self.player_hands.len();
-                    // make them draw cards
-                    let mut drawn = self.draw_n_cards(2);
-                    self.player_hands[self.current_turn].0.append(&mut drawn);
-                    // draw 2 is also skip
-                    self.current_turn = (self.current_turn + 1) % self.player_hands.len();
+                UnoValue::Draw2 => self.apply_draw_penalty(2, false)?,
+                UnoValue::Seven if self.rules.seven_zero => {
+                    // swap_with was already validated by `validate_seven_swap` above
+                    if let Some(other) = swap_with {
+                        self.player_hands.swap(self.current_turn, other);
+                    }
                 }
+                UnoValue::Zero if self.rules.seven_zero => self.rotate_hands(),
                 _ => {}
             },
             UnoCard::Wild(wild) => match wild {
-                UnoWildCard::Played { draw_4, .. } => {
-                    if draw_4 {
-                        // advance turn
-                        self.current_turn = (self.current_turn + 1) % self.player_hands.len();
-                        // make them draw cards
-                        // TODO handle discard deck recycle
-                        let mut drawn = self.draw_n_cards(4);
-                        self.player_hands[self.current_turn].0.append(&mut drawn);
-                        // draw 4 is also skip
-                        self.current_turn = (self.current_turn + 1) % self.player_hands.len();
-                    }
-                }
+                UnoWildCard::Played { draw_4: true, .. } => self.apply_draw_penalty(4, true)?,
+                UnoWildCard::Played { draw_4: false, .. } => {}
                 UnoWildCard::Unplayed { .. } => {
                     unreachable!("Already validated by playable_on function above")
                 }
             },
+        }
+        Ok(())
+    }
+
+    /// Advance past the current player, then either defer `count` cards as a pending
+    /// stack (if `self.rules.stacking`) or make them draw `count` cards immediately and
+    /// skip them too.
+    fn apply_draw_penalty(&mut self, count: usize, is_draw4: bool) -> Result<(), UnoError> {
+        self.current_turn = (self.current_turn + 1) % self.player_hands.len();
+        if self.rules.stacking {
+            // defer the draw; the next player stacks or calls pass_and_draw
+            self.pending_draw += count;
+            self.pending_draw_is_draw4 = is_draw4;
+        } else {
+            let mut drawn = self.draw_n_cards(count)?;
+            self.move_log.push(MoveLog::CardsDrawn {
+                player: self.current_turn,
+                count: drawn.len(),
+            });
+            self.player_hands[self.current_turn].0.append(&mut drawn);
+            self.current_turn = (self.current_turn + 1) % self.player_hands.len();
+        }
+        Ok(())
+    }
+
+    /// Under `self.rules.seven_zero`, pass every player's hand to the next player in
+    /// the turn direction (the effect of playing a `Zero`).
+    fn rotate_hands(&mut self) {
+        match self.turn_direction {
+            TurnDirection::Clockwise => self.player_hands.rotate_right(1),
+            TurnDirection::CounterClockwise => self.player_hands.rotate_left(1),
+        }
+    }
+
+    /// Log a `TurnChanged` event if the turn actually moved, then, if the play that
+    /// just happened emptied someone's hand, score the round and move to
+    /// [`GameStatus::Finished`] (if `winner` reached `target_score`) or
+    /// [`GameStatus::RoundOver`] (otherwise).
+    fn finish_turn(&mut self, turn_before: (usize, TurnDirection)) {
+        if (self.current_turn, self.turn_direction) != turn_before {
+            self.move_log.push(MoveLog::TurnChanged {
+                current_turn: self.current_turn,
+                direction: self.turn_direction,
+            });
+        }
+
+        if let Some(winner) = self.is_round_over() {
+            self.score_round(winner);
+            self.status = if self.scores[winner] >= self.target_score {
+                GameStatus::Finished { winner }
+            } else {
+                GameStatus::RoundOver { winner }
+            };
+        }
+    }
+
+    /// Deal a new round after the previous one ended in [`GameStatus::RoundOver`],
+    /// continuing the match with `scores` and `seed`'s RNG carried over. Resets all
+    /// other per-round state: hands, decks, turn order, and pending stacks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnoError::RoundInProgress`] if the current round hasn't ended, or
+    /// [`UnoError::NoCardsLeft`] if there aren't enough cards to deal a fresh round
+    /// (should not happen, since decks are combined as needed).
+    pub fn start_next_round(&mut self) -> Result<&mut Self, UnoError> {
+        let GameStatus::RoundOver { .. } = self.status else {
+            return Err(UnoError::RoundInProgress);
         };
 
+        let players = self.player_hands.len();
+        let mut main_deck = Self::new_combined_deck(&mut self.rng, players, self.hand_size);
+        let player_hands = main_deck.deal(players, self.hand_size)?;
+        let discard_deck = UnoDeck(vec![main_deck.draw_card().ok_or(UnoError::NoCardsLeft)?]);
+
+        self.main_deck = main_deck;
+        self.discard_deck = discard_deck;
+        self.player_hands = player_hands;
+        self.turn_direction = TurnDirection::Clockwise;
+        self.current_turn = 0; // TODO dice roll for who goes first
+        self.status = GameStatus::InProgress;
+        self.uno_called = vec![true; players];
+        self.pending_draw = 0;
+        self.pending_draw_is_draw4 = false;
+        self.move_log.push(MoveLog::RoundStarted);
+
         Ok(self)
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacking_rejects_non_matching_draw_card() {
+        let rules = GameRules {
+            stacking: true,
+            ..GameRules::default()
+        };
+        let mut state =
+            UnoGameState::new_with_seed(2, rules, 1, 7).expect("2 players, 7 cards each fits");
+        state.discard_deck.0.push(UnoCard::Card {
+            color: UnoColor::Red,
+            value: UnoValue::Draw2,
+        });
+        state.pending_draw = 2;
+        let non_matching = UnoCard::Card {
+            color: UnoColor::Blue,
+            value: UnoValue::Five,
+        };
+        state.player_hands[state.current_turn].0.push(non_matching);
+
+        let result = state.try_next(state.current_turn, &non_matching, None);
+
+        assert!(matches!(result, Err(UnoError::InvalidStackedDraw)));
+    }
+
+    #[test]
+    fn stacking_accepts_matching_draw_card() {
+        let rules = GameRules {
+            stacking: true,
+            ..GameRules::default()
+        };
+        let mut state =
+            UnoGameState::new_with_seed(2, rules, 1, 7).expect("2 players, 7 cards each fits");
+        state.discard_deck.0.push(UnoCard::Card {
+            color: UnoColor::Red,
+            value: UnoValue::Draw2,
+        });
+        state.pending_draw = 2;
+        let matching = UnoCard::Card {
+            color: UnoColor::Blue,
+            value: UnoValue::Draw2,
+        };
+        state.player_hands[state.current_turn].0.push(matching);
+
+        state
+            .try_next(state.current_turn, &matching, None)
+            .expect("a matching Draw2 should stack");
+
+        assert_eq!(state.pending_draw, 4);
+    }
+
+    fn first_playable_move(state: &UnoGameState) -> Option<UnoCard> {
+        let top = *state.discard_deck.0.last()?;
+        state.player_hands[state.current_turn].0.iter().find_map(|card| {
+            let candidate = match card {
+                UnoCard::Wild(UnoWildCard::Unplayed { draw_4 }) => UnoCard::Wild(UnoWildCard::Played {
+                    draw_4: *draw_4,
+                    color: UnoColor::Red,
+                }),
+                other => *other,
+            };
+            candidate.playable_on(&top).is_ok().then_some(candidate)
+        })
+    }
+
+    #[test]
+    fn replay_reconstructs_identical_state() {
+        let rules = GameRules::default();
+        let (seed, played_card) = (0u64..20)
+            .find_map(|seed| {
+                let state = UnoGameState::new_with_seed(3, rules, seed, 7).ok()?;
+                first_playable_move(&state).map(|card| (seed, card))
+            })
+            .expect("at least one of the first 20 seeds deals a playable opening move");
+
+        let mut state = UnoGameState::new_with_seed(3, rules, seed, 7)
+            .expect("seed already validated above");
+        let player = state.current_turn;
+        state
+            .try_next(player, &played_card, None)
+            .expect("move was checked playable above");
+
+        let replayed = UnoGameState::replay(3, rules, seed, 7, &state.move_log)
+            .expect("move log should replay cleanly");
+
+        assert_eq!(replayed.discard_deck.0, state.discard_deck.0);
+        assert_eq!(replayed.current_turn, state.current_turn);
+        assert_eq!(replayed.status, state.status);
+        assert_eq!(replayed.move_log, state.move_log);
+        for (a, b) in replayed.player_hands.iter().zip(&state.player_hands) {
+            assert_eq!(a.0, b.0);
+        }
+    }
+
+    #[test]
+    fn round_over_then_start_next_round_preserves_scores() {
+        let rules = GameRules::default();
+        let mut state = UnoGameState::new_with_target_score(2, 1000, rules)
+            .expect("2 players, generous target score");
+        let finishing_card = UnoCard::Card {
+            color: UnoColor::Red,
+            value: UnoValue::Five,
+        };
+        state.discard_deck.0 = vec![finishing_card];
+        state.player_hands[0].0 = vec![finishing_card];
+        state.current_turn = 0;
+
+        state
+            .try_next(0, &finishing_card, None)
+            .expect("player 0's only card matches the discard top");
+
+        assert!(matches!(state.status, GameStatus::RoundOver { winner: 0 }));
+        let score_after_round = state.scores[0];
+        assert!(score_after_round > 0);
+
+        state
+            .start_next_round()
+            .expect("the round just ended, so a new one can start");
+
+        assert_eq!(state.status, GameStatus::InProgress);
+        assert_eq!(state.scores[0], score_after_round);
+        assert!(state
+            .player_hands
+            .iter()
+            .all(|hand| hand.0.len() == PLAYER_STARTING_HAND_SIZE));
+        assert!(matches!(state.move_log.last(), Some(MoveLog::RoundStarted)));
+    }
+
+    #[test]
+    fn start_next_round_rejects_round_still_in_progress() {
+        let mut state = UnoGameState::new(2, GameRules::default()).expect("valid setup");
+
+        let result = state.start_next_round();
+
+        assert!(matches!(result, Err(UnoError::RoundInProgress)));
+    }
+}